@@ -0,0 +1,150 @@
+// Bivariate-polynomial Verifiable secret sharing, the symmetric DKG protocol used by hbbft.
+
+use amcl_wrapper::group_elem::{GroupElement, GroupElementVector};
+use amcl_wrapper::group_elem_g1::{G1, G1Vector};
+use amcl_wrapper::field_elem::{FieldElement, FieldElementVector};
+use std::collections::HashMap;
+use crate::polynomial::{BivarPolynomial, Polynomial};
+
+/// Commitment to the coefficients of a `BivarPolynomial`: the symmetric matrix `g^{a_ij}`, indexed
+/// by `(i, j)` with `i <= j` since `a_ij = a_ji`.
+pub type BivariateCommitment = HashMap<(usize, usize), G1>;
+
+// Bivariate-polynomial Verifiable secret sharing. A dealer-driven, publicly verifiable alternative
+// to `PedersenDVSS`'s all-participants-must-finish protocol.
+/* The basic idea is the following
+    Dealer wants to share a secret in k-of-n manner with n nodes
+    Dealer chooses a symmetric bivariate polynomial f(x, y) of degree t = k - 1 such that the shared secret is f(0, 0)
+    Dealer publishes commitment C_ij = g^{a_ij} to every coefficient of f, broadcast to all n nodes
+    Dealer sends row m, i.e. the univariate polynomial f(m, y), to node m
+    Node m sends the single value f(m, s) to node s
+    Node s validates an incoming value v from node m against the public commitment by checking g^v == prod_{i,j} (C_ij)^{m^i s^j}
+    Once 2t + 1 nodes confirm valid rows, each node reconstructs its column and recovers the value at 0 of its column
+*/
+pub struct BivariateVSS {}
+
+impl BivariateVSS {
+    /// Generator used for commitment.
+    pub fn gens(label: &[u8]) -> G1 {
+        // For NUMS.
+        G1::from_msg_hash(&[label, " : g".as_bytes()].concat())
+    }
+
+    /// Executed by dealer. Choose a symmetric bivariate polynomial of degree `threshold - 1` and
+    /// output the shared secret, the polynomial (so rows can be handed out) and the public
+    /// commitment to its coefficients.
+    pub fn deal(threshold: usize, g: &G1) -> (FieldElement, BivarPolynomial, BivariateCommitment) {
+        let poly = BivarPolynomial::random(threshold - 1);
+        let secret = poly.eval(&FieldElement::zero(), &FieldElement::zero());
+        let degree = poly.degree();
+        let commitment = (0..=degree)
+            .flat_map(|i| (i..=degree).map(move |j| (i, j)))
+            .map(|(i, j)| ((i, j), g * poly.coefficient(i, j)))
+            .collect::<BivariateCommitment>();
+        (secret, poly, commitment)
+    }
+
+    /// Executed by node `s` to check the value `f(m, s)` sent to it by node `m` against the public
+    /// commitment.
+    pub fn verify_row_value(
+        threshold: usize,
+        m: usize,
+        s: usize,
+        v: &FieldElement,
+        commitment: &BivariateCommitment,
+        g: &G1,
+    ) -> bool {
+        let degree = threshold - 1;
+
+        // m_exp = [1, m, m^2, ... m^degree], s_exp = [1, s, s^2, ... s^degree]
+        let m_exp = FieldElementVector::new_vandermonde_vector(&FieldElement::from(m as u64), degree + 1);
+        let s_exp = FieldElementVector::new_vandermonde_vector(&FieldElement::from(s as u64), degree + 1);
+
+        let mut bases = G1Vector::with_capacity((degree + 1) * (degree + 1) + 1);
+        let mut exp = FieldElementVector::with_capacity((degree + 1) * (degree + 1) + 1);
+        for i in 0..=degree {
+            for j in 0..=degree {
+                let key = if i <= j { (i, j) } else { (j, i) };
+                match commitment.get(&key) {
+                    Some(c) => bases.push(c.clone()),
+                    None => return false,
+                }
+                exp.push(&m_exp[i] * &s_exp[j]);
+            }
+        }
+
+        // g^v will need to be inverted. To do one multi-scalar multiplication, invert g
+        bases.push(g.negation());
+        exp.push(v.clone());
+
+        bases.multi_scalar_mul_var_time(&exp).unwrap().is_identity()
+    }
+
+    /// Executed by node `s` once it holds verified values `f(m, s)` from at least `threshold`
+    /// distinct nodes `m`, i.e. enough points on its column `f(x, s)` to interpolate it: recover
+    /// `f(0, s)`, node `s`'s final share of the distributed secret. The shared secret itself,
+    /// `f(0, 0)`, can later be recovered from `threshold` such shares the same way
+    /// `shamir_secret_sharing::reconstruct_secret` recovers a Shamir-shared secret.
+    pub fn reconstruct_share(threshold: usize, values: &HashMap<usize, FieldElement>) -> FieldElement {
+        assert!(values.len() >= threshold);
+        let points = values
+            .iter()
+            .take(threshold)
+            .map(|(m, v)| (FieldElement::from(*m as u64), v.clone()))
+            .collect::<Vec<(FieldElement, FieldElement)>>();
+        Polynomial::interpolate(&points).eval(&FieldElement::zero())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shamir_secret_sharing::reconstruct_secret;
+
+    #[test]
+    fn test_Bivariate_VSS() {
+        let threshold = 5;
+        let total = 10;
+        let g = BivariateVSS::gens("test".as_bytes());
+        let (secret, poly, commitment) = BivariateVSS::deal(threshold, &g);
+
+        // The dealer hands row m to node m; each node only ever sees its own row, never `poly`.
+        let rows = (1..=total)
+            .map(|m| (m, poly.row(m)))
+            .collect::<HashMap<usize, Polynomial>>();
+
+        // Node m sends f(m, s) to node s from its own row; node s verifies it against the public
+        // commitment before accepting it as a point on its column f(x, s).
+        let mut columns = (1..=total)
+            .map(|s| (s, HashMap::new()))
+            .collect::<HashMap<usize, HashMap<usize, FieldElement>>>();
+        for (m, row) in &rows {
+            for s in 1..=total {
+                let v = row.eval(&FieldElement::from(s as u64));
+                assert!(BivariateVSS::verify_row_value(
+                    threshold,
+                    *m,
+                    s,
+                    &v,
+                    &commitment,
+                    &g
+                ));
+                columns.get_mut(&s).unwrap().insert(*m, v);
+            }
+        }
+
+        // Every node reconstructs its column to recover its final share f(0, s).
+        let shares = columns
+            .into_iter()
+            .map(|(s, values)| (s, BivariateVSS::reconstruct_share(threshold, &values)))
+            .collect::<HashMap<usize, FieldElement>>();
+
+        // Any `threshold` of those shares recover the distributed secret f(0, 0), same as Shamir.
+        let recon_secret = reconstruct_secret(
+            threshold,
+            shares.into_iter().take(threshold).collect::<HashMap<usize, FieldElement>>(),
+        );
+        assert_eq!(secret, recon_secret);
+        assert_eq!(secret, poly.eval(&FieldElement::zero(), &FieldElement::zero()));
+    }
+}
@@ -0,0 +1,109 @@
+// Feldman Verifiable secret sharing
+
+use amcl_wrapper::group_elem::{GroupElement, GroupElementVector};
+use amcl_wrapper::group_elem_g1::{G1, G1Vector};
+use amcl_wrapper::field_elem::{FieldElement, FieldElementVector};
+use std::collections::HashMap;
+use crate::shamir_secret_sharing::get_shared_secret_with_polynomial;
+
+// Feldman Verifiable secret sharing. A lighter-weight alternative to `PedersenVSS` that needs only
+// a single generator and no blinding polynomial, at the cost of computational (rather than
+// information-theoretic) hiding of the secret.
+/* The basic idea is the following
+    Dealer wants to share a secret s in k-of-n manner with n participants
+    Create polynomial F(x) = s + F_1.x + F_2.x^2 + ... F_{k-1}.x^{k-1} such that F(0) = s.
+    Commits to coefficients as C_0 = g^s, C_1 = g^F_1, C_2 = g^F_2,... till C_{k-1} = g^F_{k-1}, broadcast to all n participants
+    Dealer sends F(i) to participant i
+    Each participant verifies g^F(i) = C_0 * C_1^i * C_2^{i^2} * ... C_{k-1}^{i^{k-1}}
+*/
+pub struct FeldmanVSS {}
+
+impl FeldmanVSS {
+    /// Generator used for commitment.
+    pub fn gens(label: &[u8]) -> G1 {
+        // For NUMS.
+        G1::from_msg_hash(&[label, " : g".as_bytes()].concat())
+    }
+
+    /// Executed by dealer. Output secret, commitment to coefficients and shares for each participant.
+    /// Each participant has access to all commitments to coefficients but only to its own share.
+    pub fn deal(
+        threshold: usize,
+        total: usize,
+        g: &G1,
+    ) -> (
+        FieldElement,                 // secret
+        HashMap<usize, G1>,           // commitment to coefficients
+        HashMap<usize, FieldElement>, // shares for secret
+    ) {
+        let (s, s_shares, s_poly) = get_shared_secret_with_polynomial(threshold, total);
+        // map of i -> g^s_poly.coefficients[i]
+        let commitment_coeffs = (0..threshold)
+            .map(|i| (i, g * &s_poly.coefficients()[i]))
+            .collect::<HashMap<usize, G1>>();
+        (s, commitment_coeffs, s_shares)
+    }
+
+    /// Executed by each participant to verify its share received from the dealer.
+    pub fn verify_share(
+        threshold: usize,
+        id: usize,
+        share: &FieldElement,
+        commitment_coeffs: &HashMap<usize, G1>,
+        g: &G1,
+    ) -> bool {
+        assert!(commitment_coeffs.len() >= threshold);
+        // Check commitment_coeffs[0] * commitment_coeffs[1]^id * ... commitment_coeffs[threshold-1]^{id^threshold-1} == g^share
+        // => commitment_coeffs[0] * commitment_coeffs[1]^id * ... commitment_coeffs[threshold-1]^{id^threshold-1} * {g^share}^-1 == 1
+
+        // exp will be [1, id, id^2, ... id^threshold-1]
+        let mut exp =
+            FieldElementVector::new_vandermonde_vector(&FieldElement::from(id as u64), threshold);
+
+        // add share to exp
+        exp.push(share.clone());
+
+        let mut bases = G1Vector::with_capacity(threshold + 1);
+        for i in 0..threshold {
+            bases.push(commitment_coeffs[&i].clone())
+        }
+
+        // g^share will need to be inverted. To do one multi-scalar multiplication, invert g
+        bases.push(g.negation());
+
+        bases.multi_scalar_mul_var_time(&exp).unwrap().is_identity()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shamir_secret_sharing::reconstruct_secret;
+
+    #[test]
+    fn test_Feldman_VSS() {
+        let threshold = 5;
+        let total = 10;
+        let g = FeldmanVSS::gens("test".as_bytes());
+        let (secret, comm_coeffs, s_shares) = FeldmanVSS::deal(threshold, total, &g);
+        assert_eq!(s_shares.len(), total);
+        assert_eq!(comm_coeffs.len(), threshold);
+        for i in 1..=total {
+            assert!(FeldmanVSS::verify_share(
+                threshold,
+                i,
+                &s_shares[&i],
+                &comm_coeffs,
+                &g
+            ));
+        }
+        let recon_secret = reconstruct_secret(
+            threshold,
+            s_shares
+                .into_iter()
+                .take(threshold)
+                .collect::<HashMap<usize, FieldElement>>(),
+        );
+        assert_eq!(secret, recon_secret);
+    }
+}
@@ -3,4 +3,6 @@
 pub mod polynomial;
 pub mod shamir_secret_sharing;
 pub mod pedersen_vss;
-pub mod pedersen_dvss;
\ No newline at end of file
+pub mod pedersen_dvss;
+pub mod feldman_vss;
+pub mod bivariate_vss;
\ No newline at end of file
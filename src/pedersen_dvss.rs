@@ -2,8 +2,9 @@
 
 use amcl_wrapper::group_elem::GroupElement;
 use amcl_wrapper::group_elem_g1::G1;
-use amcl_wrapper::field_elem::{FieldElement, FieldElementVector};
-use std::collections::HashMap;
+use amcl_wrapper::field_elem::FieldElement;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
 use crate::pedersen_vss::PedersenVSS;
 
 
@@ -19,24 +20,118 @@ use crate::pedersen_vss::PedersenVSS;
     After each of the n participants has successfully runs a VSS, they generate their corresponding share of s by adding
     their shares of each s_i_0 for i in 1 to n.
 */
-// TODO: Model the code as state machine
-pub struct PedersenDVSSParticipant {
+// Modelled as a typestate chain (as in frost-dalek's keygen) so illegal call orders, like computing
+// the final share before collecting every other participant's share, are unrepresentable at compile
+// time. A `Participant<RoundOne>` collects shares one at a time, raising a complaint instead of
+// aborting when a share fails verification, transitions to `Participant<RoundTwo>` once a qualified
+// set `Q` of senders has been agreed on, and finally to `Participant<Finished>` which exposes
+// `secret_share` and `Q`.
+//
+// A participant whose share drew a complaint (or who never answers one) is excluded from `Q` by
+// whatever transport/consensus layer collects complaints across all participants; `finish_receiving`
+// just needs to be told the resulting `disqualified` set. This tolerates up to `total - threshold`
+// cheating or aborting participants instead of requiring all `total` to finish.
+
+/// Errors that can occur while running the protocol.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DVSSError {
+    /// A share from `sender_id` was already received.
+    DuplicateShare { sender_id: usize },
+    /// `sender_id` is not a valid participant id for the given `total`.
+    UnknownSender { sender_id: usize },
+    /// `finish_receiving` was called before shares from every qualified sender (other than self) arrived.
+    IncompleteShares { received: usize, expected: usize },
+    /// The qualified set `Q` left after disqualification is smaller than `threshold`.
+    TooFewQualified { qualified: usize, threshold: usize },
+    /// The final combined share failed verification against the final commitment coefficients.
+    InvalidFinalShare,
+    /// A zero-share received from `sender_id` during a refresh failed verification.
+    InvalidZeroShare { sender_id: usize },
+    /// `sender_id`'s commitment during a refresh does not open to a zero constant term, i.e. it is
+    /// not actually a sharing of zero and would change the distributed secret if applied.
+    InvalidZeroCommitment { sender_id: usize },
+}
+
+impl fmt::Display for DVSSError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DVSSError::DuplicateShare { sender_id } => {
+                write!(f, "already received a share from participant {}", sender_id)
+            }
+            DVSSError::UnknownSender { sender_id } => {
+                write!(f, "{} is not a valid participant id", sender_id)
+            }
+            DVSSError::IncompleteShares { received, expected } => write!(
+                f,
+                "received shares from {} qualified participants, expected {}",
+                received, expected
+            ),
+            DVSSError::TooFewQualified { qualified, threshold } => write!(
+                f,
+                "only {} participants are qualified, need at least {}",
+                qualified, threshold
+            ),
+            DVSSError::InvalidFinalShare => {
+                write!(f, "final combined share failed verification")
+            }
+            DVSSError::InvalidZeroShare { sender_id } => write!(
+                f,
+                "zero-share from participant {} failed verification",
+                sender_id
+            ),
+            DVSSError::InvalidZeroCommitment { sender_id } => write!(
+                f,
+                "commitment from participant {} does not open to a zero constant term",
+                sender_id
+            ),
+        }
+    }
+}
+
+impl std::error::Error for DVSSError {}
+
+/// State of a `Participant` that is still collecting shares from other participants.
+#[derive(Debug)]
+pub struct RoundOne {
+    all_comm_coeffs: HashMap<usize, HashMap<usize, G1>>,
+    all_shares: HashMap<usize, (FieldElement, FieldElement)>,
+    /// Senders whose share failed verification, i.e. this participant's complaints.
+    complaints: HashSet<usize>,
+}
+
+/// State of a `Participant` that has agreed on the qualified set `Q` and is ready to compute its
+/// share of the distributed secret.
+#[derive(Debug)]
+pub struct RoundTwo {
+    all_comm_coeffs: HashMap<usize, HashMap<usize, G1>>,
+    all_shares: HashMap<usize, (FieldElement, FieldElement)>,
+    qualified: HashSet<usize>,
+}
+
+/// State of a `Participant` that has computed its final share of the distributed secret.
+#[derive(Debug)]
+pub struct Finished {
+    final_comm_coeffs: HashMap<usize, G1>,
+    secret_share: FieldElement,
+    qualified: HashSet<usize>,
+}
+
+/// A participant in the decentralized VSS protocol. The type parameter tracks how far the
+/// participant has progressed, so e.g. `secret_share` is only reachable on `Participant<Finished>`.
+#[derive(Debug)]
+pub struct Participant<S> {
     pub id: usize,
     pub secret: FieldElement,
     pub comm_coeffs: HashMap<usize, G1>,
     pub s_shares: HashMap<usize, FieldElement>,
     pub t_shares: HashMap<usize, FieldElement>,
-    all_comm_coeffs: HashMap<usize, HashMap<usize, G1>>,
-    all_shares: HashMap<usize, (FieldElement, FieldElement)>,
-    // XXX: Should be in a different struct if the protocol is modelled as a state machine
-    pub final_comm_coeffs: HashMap<usize, G1>,
-    pub secret_share: FieldElement,
+    state: S,
 }
 
-impl PedersenDVSSParticipant {
+impl Participant<RoundOne> {
     /// Generates a new secret and verifiable shares of that secret for every participant
     pub fn new(id: usize, threshold: usize, total: usize, g: &G1, h: &G1) -> Self {
-        let (secret, _, comm_coeffs, s_shares, t_shares) =
+        let (secret, _blinding, comm_coeffs, s_shares, t_shares) =
             PedersenVSS::deal(threshold, total, &g, &h);
         // TODO: As mentioned in the paper, there should be a signature from the participant for non-repudiation
         Self {
@@ -45,16 +140,21 @@ impl PedersenDVSSParticipant {
             comm_coeffs,
             s_shares,
             t_shares,
-            all_comm_coeffs: HashMap::new(),
-            all_shares: HashMap::new(),
-            final_comm_coeffs: HashMap::new(),
-            secret_share: FieldElement::new(),
+            state: RoundOne {
+                all_comm_coeffs: HashMap::new(),
+                all_shares: HashMap::new(),
+                complaints: HashSet::new(),
+            },
         }
     }
 
-    /// Called by a participant when it receives a share from another participant with id `sender_id`
+    /// Called by a participant when it receives a share from another participant with id `sender_id`.
+    /// Consumes and returns `self` so calls can be chained while collecting shares. A share that is a
+    /// duplicate or from an unknown sender is a programmer error and returns `Err`; a share that fails
+    /// verification instead raises a complaint against `sender_id` (see `complaints`) so a faulty or
+    /// cheating participant can be disqualified rather than aborting the whole protocol.
     pub fn received_share(
-        &mut self,
+        mut self,
         sender_id: usize,
         comm_coeffs: HashMap<usize, G1>,
         share: (FieldElement, FieldElement),
@@ -62,117 +162,275 @@ impl PedersenDVSSParticipant {
         total: usize,
         g: &G1,
         h: &G1,
-    ) {
-        assert!(sender_id <= total);
-        assert!(!self.all_comm_coeffs.contains_key(&sender_id));
-        assert!(!self.all_shares.contains_key(&sender_id));
+    ) -> Result<Self, DVSSError> {
+        if sender_id < 1 || sender_id > total {
+            return Err(DVSSError::UnknownSender { sender_id });
+        }
+        if self.state.all_comm_coeffs.contains_key(&sender_id)
+            || self.state.all_shares.contains_key(&sender_id)
+            || self.state.complaints.contains(&sender_id)
+        {
+            return Err(DVSSError::DuplicateShare { sender_id });
+        }
         // Verify received share
-        assert!(PedersenVSS::verify_share(
+        if !PedersenVSS::verify_share(
             threshold,
             self.id,
             (&share.0, &share.1),
             &comm_coeffs,
             &g,
-            &h
-        ));
-        self.all_comm_coeffs.insert(sender_id, comm_coeffs);
-        self.all_shares.insert(sender_id, share);
+            &h,
+        ) {
+            self.state.complaints.insert(sender_id);
+            return Ok(self);
+        }
+        self.state.all_comm_coeffs.insert(sender_id, comm_coeffs);
+        self.state.all_shares.insert(sender_id, share);
+        Ok(self)
     }
 
-    /// Called by a participant when it has received shares from all participants. Computes the final
-    /// share of the distributed secret
+    /// Senders whose share this participant has complained against.
+    pub fn complaints(&self) -> &HashSet<usize> {
+        &self.state.complaints
+    }
+
+    /// Transition to `RoundTwo` once the qualified set `Q` (every participant id `1..=total` except
+    /// `disqualified`) has been agreed on and a verified share has arrived from every other member of
+    /// `Q`. `disqualified` is the union, across all participants, of complaints raised (or left
+    /// unanswered) in the complaint round; it is supplied by the caller since collecting it requires
+    /// a further round of communication this type does not model.
+    pub fn finish_receiving(
+        self,
+        total: usize,
+        threshold: usize,
+        disqualified: &HashSet<usize>,
+    ) -> Result<Participant<RoundTwo>, DVSSError> {
+        let qualified = (1..=total)
+            .filter(|id| !disqualified.contains(id))
+            .collect::<HashSet<usize>>();
+        if qualified.len() < threshold {
+            return Err(DVSSError::TooFewQualified {
+                qualified: qualified.len(),
+                threshold,
+            });
+        }
+        let expected = qualified.len() - if qualified.contains(&self.id) { 1 } else { 0 };
+        let received = qualified
+            .iter()
+            .filter(|id| **id != self.id && self.state.all_shares.contains_key(*id))
+            .count();
+        if received != expected {
+            return Err(DVSSError::IncompleteShares { received, expected });
+        }
+        Ok(Participant {
+            id: self.id,
+            secret: self.secret,
+            comm_coeffs: self.comm_coeffs,
+            s_shares: self.s_shares,
+            t_shares: self.t_shares,
+            state: RoundTwo {
+                all_comm_coeffs: self.state.all_comm_coeffs,
+                all_shares: self.state.all_shares,
+                qualified,
+            },
+        })
+    }
+}
+
+impl Participant<RoundTwo> {
+    /// Computes the final share of the distributed secret and the commitment to its coefficients,
+    /// summing only over the qualified set `Q` agreed on in `finish_receiving`.
     pub fn compute_final_comm_coeffs_and_shares(
-        &mut self,
+        self,
         threshold: usize,
-        total: usize,
         g: &G1,
         h: &G1,
-    ) {
-        assert_eq!(self.all_comm_coeffs.len(), total - 1);
-        assert_eq!(self.all_shares.len(), total - 1);
+    ) -> Result<Participant<Finished>, DVSSError> {
+        let qualified = &self.state.qualified;
 
         // Compute own share and commitment to coefficients of the distributed secret.
+        let mut final_comm_coeffs = HashMap::new();
         for i in 0..threshold {
-            // cm is the sum of coefficients of each signer's polynomial's ith degree term
+            // cm is the sum of coefficients of each qualified signer's polynomial's ith degree term
             let mut cm = G1::identity();
-            for j in 1..=total {
-                if j != self.id {
-                    cm += self.all_comm_coeffs[&j].get(&i).unwrap();
+            for j in qualified {
+                if *j != self.id {
+                    cm += self.state.all_comm_coeffs[j].get(&i).unwrap();
                 } else {
                     cm += self.comm_coeffs.get(&i).unwrap();
                 }
             }
-            self.final_comm_coeffs.insert(i, cm);
+            final_comm_coeffs.insert(i, cm);
         }
 
         let mut final_s_share = FieldElement::zero();
         let mut final_t_share = FieldElement::zero();
-        for i in 1..=total {
-            let (s, t) = if i != self.id {
-                let tpl = &self.all_shares[&i];
+        for i in qualified {
+            let (s, t) = if *i != self.id {
+                let tpl = &self.state.all_shares[i];
                 (&tpl.0, &tpl.1)
             } else {
-                (&self.s_shares[&i], &self.t_shares[&i])
+                (&self.s_shares[i], &self.t_shares[i])
             };
             final_s_share += s;
             final_t_share += t;
         }
 
         // Verify computed share of the distributed secret
-        assert!(PedersenVSS::verify_share(
+        if !PedersenVSS::verify_share(
             threshold,
             self.id,
             (&final_s_share, &final_t_share),
-            &self.final_comm_coeffs,
+            &final_comm_coeffs,
             &g,
-            &h
-        ));
+            &h,
+        ) {
+            return Err(DVSSError::InvalidFinalShare);
+        }
+
+        Ok(Participant {
+            id: self.id,
+            secret: self.secret,
+            comm_coeffs: self.comm_coeffs,
+            s_shares: self.s_shares,
+            t_shares: self.t_shares,
+            state: Finished {
+                final_comm_coeffs,
+                secret_share: final_s_share,
+                qualified: self.state.qualified,
+            },
+        })
+    }
+}
+
+impl Participant<Finished> {
+    pub fn final_comm_coeffs(&self) -> &HashMap<usize, G1> {
+        &self.state.final_comm_coeffs
+    }
+
+    pub fn secret_share(&self) -> &FieldElement {
+        &self.state.secret_share
+    }
+
+    /// The qualified set `Q` whose contributions formed the distributed secret.
+    pub fn qualified(&self) -> &HashSet<usize> {
+        &self.state.qualified
+    }
 
-        self.secret_share = final_s_share;
+    /// Proactively refresh this participant's share: add the sum of zero-shares received from every
+    /// qualified participant, including this one's own (each dealt via `PedersenVSS::deal_zero`),
+    /// verifying each one against its commitment first. `zero_blindings` carries the blinding each
+    /// sender opened for its commitment's constant term (see `PedersenVSS::verify_zero_commitment`)
+    /// — without checking it, a sender could hand out an ordinary, non-zero `PedersenVSS::deal`ing
+    /// and `refresh` would fold its secret into every share none the wiser. Every zero-polynomial
+    /// evaluates to 0 at `x = 0`, so the secret reconstructed from the refreshed shares is unchanged,
+    /// but a share captured before this call cannot be combined with shares captured after it.
+    pub fn refresh(
+        self,
+        threshold: usize,
+        zero_comm_coeffs: &HashMap<usize, HashMap<usize, G1>>,
+        zero_shares: &HashMap<usize, (FieldElement, FieldElement)>,
+        zero_blindings: &HashMap<usize, FieldElement>,
+        g: &G1,
+        h: &G1,
+    ) -> Result<Self, DVSSError> {
+        let mut refreshed_share = self.state.secret_share.clone();
+        let mut refreshed_comm_coeffs = self.state.final_comm_coeffs.clone();
+        for (sender_id, share) in zero_shares {
+            let comm_coeffs = &zero_comm_coeffs[sender_id];
+            if !PedersenVSS::verify_zero_commitment(comm_coeffs, &zero_blindings[sender_id], g, h) {
+                return Err(DVSSError::InvalidZeroCommitment {
+                    sender_id: *sender_id,
+                });
+            }
+            if !PedersenVSS::verify_share(threshold, self.id, (&share.0, &share.1), comm_coeffs, g, h) {
+                return Err(DVSSError::InvalidZeroShare {
+                    sender_id: *sender_id,
+                });
+            }
+            refreshed_share += &share.0;
+            for i in 0..threshold {
+                *refreshed_comm_coeffs.get_mut(&i).unwrap() += comm_coeffs.get(&i).unwrap();
+            }
+        }
+        Ok(Participant {
+            id: self.id,
+            secret: self.secret,
+            comm_coeffs: self.comm_coeffs,
+            s_shares: self.s_shares,
+            t_shares: self.t_shares,
+            state: Finished {
+                final_comm_coeffs: refreshed_comm_coeffs,
+                secret_share: refreshed_share,
+                qualified: self.state.qualified,
+            },
+        })
     }
 }
 
 /// Create participants that take part in a decentralized secret sharing and perform the secret sharing.
+/// `faulty` senders send a bogus share to everyone, so they end up in every other participant's
+/// complaint set and are disqualified.
 #[cfg(test)]
 pub fn share_secret_for_testing(
     threshold: usize,
     total: usize,
+    faulty: &HashSet<usize>,
     g: &G1,
     h: &G1,
-) -> Vec<PedersenDVSSParticipant> {
+) -> Vec<Participant<Finished>> {
     let mut participants = vec![];
 
     // Each participant generates a new secret and verifiable shares of that secret for everyone.
     for i in 1..=total {
-        let p = PedersenDVSSParticipant::new(i, threshold, total, g, h);
+        let p = Participant::<RoundOne>::new(i, threshold, total, g, h);
         participants.push(p);
     }
 
-    // Every participant gives shares of its secret to others
-    for i in 0..total {
-        for j in 0..total {
-            if i == j {
+    // Every participant gives shares of its secret to others; a faulty sender sends garbage instead.
+    let outgoing = participants
+        .iter()
+        .map(|p| (p.id, p.comm_coeffs.clone(), p.s_shares.clone(), p.t_shares.clone()))
+        .collect::<Vec<_>>();
+
+    let mut round_one = vec![];
+    for mut recv_p in participants {
+        for (id, comm_coeffs, s_shares, t_shares) in &outgoing {
+            if *id == recv_p.id {
                 continue;
             }
-            let (id, comm_coeffs, (s, t)) = (
-                participants[j].id.clone(),
-                participants[j].comm_coeffs.clone(),
-                (
-                    participants[j].s_shares[&(i + 1)].clone(),
-                    participants[j].t_shares[&(i + 1)].clone(),
-                ),
-            );
-
-            let recv_p = &mut participants[i];
-            recv_p.received_share(id, comm_coeffs, (s, t), threshold, total, g, h);
+            let share = if faulty.contains(id) {
+                (FieldElement::random(), FieldElement::random())
+            } else {
+                (s_shares[&recv_p.id].clone(), t_shares[&recv_p.id].clone())
+            };
+            recv_p = recv_p
+                .received_share(*id, comm_coeffs.clone(), share, threshold, total, g, h)
+                .unwrap();
         }
+        round_one.push(recv_p);
     }
 
-    // Every participant computes its share to the distributed secret.
-    for i in 0..total {
-        participants[i].compute_final_comm_coeffs_and_shares(threshold, total, g, h);
+    // The disqualified set is whatever any participant complained about, agreed on by all.
+    let disqualified = round_one
+        .iter()
+        .flat_map(|p| p.complaints().clone())
+        .collect::<HashSet<usize>>();
+
+    let mut finished = vec![];
+    for p in round_one {
+        if faulty.contains(&p.id) {
+            continue;
+        }
+        let round_two = p.finish_receiving(total, threshold, &disqualified).unwrap();
+        // Every participant computes its share to the distributed secret.
+        finished.push(
+            round_two
+                .compute_final_comm_coeffs_and_shares(threshold, g, h)
+                .unwrap(),
+        );
     }
-    participants
+    finished
 }
 
 #[cfg(test)]
@@ -185,7 +443,7 @@ mod tests {
         let threshold = 5;
         let total = 10;
         let (g, h) = PedersenVSS::gens("test".as_bytes());
-        let participants = share_secret_for_testing(threshold, total, &g, &h);
+        let participants = share_secret_for_testing(threshold, total, &HashSet::new(), &g, &h);
 
         let mut expected_shared_secret = FieldElement::zero();
         for p in &participants {
@@ -193,7 +451,7 @@ mod tests {
         }
         let mut shares = HashMap::new();
         for i in 0..threshold {
-            shares.insert(participants[i].id, participants[i].secret_share.clone());
+            shares.insert(participants[i].id, participants[i].secret_share().clone());
         }
 
         // Verify that the secret can be recomputed.
@@ -201,4 +459,159 @@ mod tests {
 
         assert_eq!(expected_shared_secret, recon_secret);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_Pedersen_DVSS_invalid_share_raises_complaint() {
+        let threshold = 3;
+        let total = 4;
+        let (g, h) = PedersenVSS::gens("test".as_bytes());
+        let p1 = Participant::<RoundOne>::new(1, threshold, total, &g, &h);
+        let p2 = Participant::<RoundOne>::new(2, threshold, total, &g, &h);
+
+        let bad_share = (FieldElement::random(), FieldElement::random());
+        let p1 = p1
+            .received_share(2, p2.comm_coeffs.clone(), bad_share, threshold, total, &g, &h)
+            .unwrap();
+        assert!(p1.complaints().contains(&2));
+    }
+
+    #[test]
+    fn test_Pedersen_DVSS_rejects_out_of_range_sender_id() {
+        let threshold = 3;
+        let total = 4;
+        let (g, h) = PedersenVSS::gens("test".as_bytes());
+        let p1 = Participant::<RoundOne>::new(1, threshold, total, &g, &h);
+        let share = (FieldElement::random(), FieldElement::random());
+
+        let result = p1.received_share(0, HashMap::new(), share, threshold, total, &g, &h);
+        assert_eq!(
+            result.unwrap_err(),
+            DVSSError::UnknownSender { sender_id: 0 }
+        );
+    }
+
+    #[test]
+    fn test_Pedersen_DVSS_tolerates_disqualified_participants() {
+        let threshold = 5;
+        let total = 10;
+        let (g, h) = PedersenVSS::gens("test".as_bytes());
+        // Two participants misbehave; the remaining 8 are still >= threshold.
+        let faulty = vec![3, 7].into_iter().collect::<HashSet<usize>>();
+        let participants = share_secret_for_testing(threshold, total, &faulty, &g, &h);
+
+        assert_eq!(participants.len(), total - faulty.len());
+        for p in &participants {
+            assert!(p.qualified().is_disjoint(&faulty));
+        }
+
+        let mut expected_shared_secret = FieldElement::zero();
+        for id in participants[0].qualified() {
+            expected_shared_secret += &participants.iter().find(|p| p.id == *id).unwrap().secret;
+        }
+        let mut shares = HashMap::new();
+        for i in 0..threshold {
+            shares.insert(participants[i].id, participants[i].secret_share().clone());
+        }
+        let recon_secret = reconstruct_secret(threshold, shares);
+
+        assert_eq!(expected_shared_secret, recon_secret);
+    }
+
+    #[test]
+    fn test_Pedersen_DVSS_refresh() {
+        let threshold = 5;
+        let total = 10;
+        let (g, h) = PedersenVSS::gens("test".as_bytes());
+        let participants = share_secret_for_testing(threshold, total, &HashSet::new(), &g, &h);
+
+        let pre_refresh_shares = participants
+            .iter()
+            .map(|p| (p.id, p.secret_share().clone()))
+            .collect::<HashMap<usize, FieldElement>>();
+
+        // Every participant deals a fresh k-of-n sharing of zero.
+        let dealt = participants
+            .iter()
+            .map(|p| (p.id, PedersenVSS::deal_zero(threshold, total, &g, &h)))
+            .collect::<HashMap<
+                usize,
+                (HashMap<usize, G1>, HashMap<usize, FieldElement>, HashMap<usize, FieldElement>, FieldElement),
+            >>();
+
+        let refreshed = participants
+            .into_iter()
+            .map(|p| {
+                let zero_comm_coeffs = dealt
+                    .iter()
+                    .map(|(sender_id, (comm_coeffs, _, _, _))| (*sender_id, comm_coeffs.clone()))
+                    .collect::<HashMap<usize, HashMap<usize, G1>>>();
+                let zero_shares = dealt
+                    .iter()
+                    .map(|(sender_id, (_, s_shares, t_shares, _))| {
+                        (*sender_id, (s_shares[&p.id].clone(), t_shares[&p.id].clone()))
+                    })
+                    .collect::<HashMap<usize, (FieldElement, FieldElement)>>();
+                let zero_blindings = dealt
+                    .iter()
+                    .map(|(sender_id, (_, _, _, zero_blinding))| (*sender_id, zero_blinding.clone()))
+                    .collect::<HashMap<usize, FieldElement>>();
+                p.refresh(threshold, &zero_comm_coeffs, &zero_shares, &zero_blindings, &g, &h)
+                    .unwrap()
+            })
+            .collect::<Vec<_>>();
+
+        // Every individual share has changed...
+        for p in &refreshed {
+            assert_ne!(p.secret_share(), &pre_refresh_shares[&p.id]);
+        }
+
+        // ...but the reconstructed secret has not.
+        let pre_recon = reconstruct_secret(
+            threshold,
+            pre_refresh_shares.into_iter().take(threshold).collect(),
+        );
+        let mut post_refresh_shares = HashMap::new();
+        for i in 0..threshold {
+            post_refresh_shares.insert(refreshed[i].id, refreshed[i].secret_share().clone());
+        }
+        let post_recon = reconstruct_secret(threshold, post_refresh_shares);
+
+        assert_eq!(pre_recon, post_recon);
+    }
+
+    #[test]
+    fn test_Pedersen_DVSS_refresh_rejects_non_zero_commitment() {
+        let threshold = 5;
+        let total = 10;
+        let (g, h) = PedersenVSS::gens("test".as_bytes());
+        let mut participants = share_secret_for_testing(threshold, total, &HashSet::new(), &g, &h);
+        let p = participants.remove(0);
+
+        // An attacker deals an ordinary, non-zero sharing and tries to pass it off as a `deal_zero`
+        // output; the opened blinding it ships is the one paired with its real (non-zero) secret.
+        let (_, attacker_blinding, attacker_comm_coeffs, attacker_s_shares, attacker_t_shares) =
+            PedersenVSS::deal(threshold, total, &g, &h);
+
+        let zero_comm_coeffs = vec![(1usize, attacker_comm_coeffs.clone())]
+            .into_iter()
+            .collect::<HashMap<usize, HashMap<usize, G1>>>();
+        let zero_shares = vec![(
+            1usize,
+            (
+                attacker_s_shares[&p.id].clone(),
+                attacker_t_shares[&p.id].clone(),
+            ),
+        )]
+        .into_iter()
+        .collect::<HashMap<usize, (FieldElement, FieldElement)>>();
+        let zero_blindings = vec![(1usize, attacker_blinding)]
+            .into_iter()
+            .collect::<HashMap<usize, FieldElement>>();
+
+        let result = p.refresh(threshold, &zero_comm_coeffs, &zero_shares, &zero_blindings, &g, &h);
+        assert_eq!(
+            result.unwrap_err(),
+            DVSSError::InvalidZeroCommitment { sender_id: 1 }
+        );
+    }
+}
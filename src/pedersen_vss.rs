@@ -4,7 +4,7 @@ use amcl_wrapper::group_elem::{GroupElement, GroupElementVector};
 use amcl_wrapper::group_elem_g1::{G1, G1Vector};
 use amcl_wrapper::field_elem::{FieldElement, FieldElementVector};
 use std::collections::HashMap;
-use crate::shamir_secret_sharing::get_shared_secret_with_polynomial;
+use crate::shamir_secret_sharing::{get_shared_secret_with_polynomial, get_shares_of_zero_with_polynomial};
 
 // Pedersen Verifiable secret sharing. Based on the paper "Non-interactive and information-theoretic
 // secure verifiable secret sharing", section 4. https://www.cs.cornell.edu/courses/cs754/2001fa/129.PDF.
@@ -59,7 +59,61 @@ impl PedersenVSS {
         (s, t, commitment_coeffs, s_shares, t_shares)
     }
 
-    /// Executed by each participant to verify its share received from the dealer.
+    /// Like `deal` but forces the secret to 0, i.e. a `k`-of-`n` sharing of zero with the same
+    /// Pedersen commitments so others can verify a share against the constant term without learning
+    /// it. Also reveals the blinding of the constant term: since the shared value is public
+    /// knowledge (zero), there is nothing to hide by opening `commitment_coeffs[0]`, and doing so
+    /// lets every recipient check with `verify_zero_commitment` that this is really a sharing of
+    /// zero rather than of some other, attacker-chosen secret. Used for proactive share refresh,
+    /// where every participant deals a fresh sharing of zero and the results are summed into
+    /// existing shares.
+    pub fn deal_zero(
+        threshold: usize,
+        total: usize,
+        g: &G1,
+        h: &G1,
+    ) -> (
+        HashMap<usize, G1>,           // commitment to coefficients
+        HashMap<usize, FieldElement>, // shares of the zero secret
+        HashMap<usize, FieldElement>, // shares of the blinding
+        FieldElement,                 // blinding of the constant term, opened for verify_zero_commitment
+    ) {
+        let (s_shares, s_poly) = get_shares_of_zero_with_polynomial(threshold, total);
+        let (_, t_shares, t_poly) = get_shared_secret_with_polynomial(threshold, total);
+        let commitment_coeffs = (0..threshold)
+            .map(|i| {
+                (
+                    i,
+                    g.binary_scalar_mul(&h, &s_poly.coefficients()[i], &t_poly.coefficients()[i]),
+                )
+            })
+            .collect::<HashMap<usize, G1>>();
+        let zero_blinding = t_poly.coefficients()[0].clone();
+        (commitment_coeffs, s_shares, t_shares, zero_blinding)
+    }
+
+    /// Verify that `commitment_coeffs` commits to a constant term of 0, given the blinding for that
+    /// term opened by `deal_zero`. Without this check, `commitment_coeffs[0] = g^s.h^t` for any
+    /// secret `s` is indistinguishable from a zero commitment to a participant who only runs
+    /// `verify_share`, since that only checks a share is consistent with the published commitment,
+    /// not that the committed constant term is actually 0. `commitment_coeffs` comes from another
+    /// participant and so may be malformed (e.g. missing key `0`); that must draw a `false` rather
+    /// than a panic so the caller can report it as a bad commitment, not crash.
+    pub fn verify_zero_commitment(
+        commitment_coeffs: &HashMap<usize, G1>,
+        zero_blinding: &FieldElement,
+        g: &G1,
+        h: &G1,
+    ) -> bool {
+        match commitment_coeffs.get(&0) {
+            Some(c) => *c == g.binary_scalar_mul(&h, &FieldElement::zero(), zero_blinding),
+            None => false,
+        }
+    }
+
+    /// Executed by each participant to verify its share received from the dealer. `commitment_coeffs`
+    /// comes from another participant and so may be malformed (too few entries, or keyed outside
+    /// `0..threshold`); that must draw a `false` like any other bad share, never a panic.
     pub fn verify_share(
         threshold: usize,
         id: usize,
@@ -68,7 +122,9 @@ impl PedersenVSS {
         g: &G1,
         h: &G1,
     ) -> bool {
-        assert!(commitment_coeffs.len() >= threshold);
+        if commitment_coeffs.len() < threshold {
+            return false;
+        }
         // Check commitment_coeffs[0] * commitment_coeffs[1]^id * commitment_coeffs[2]^{id^2} * ... commitment_coeffs[threshold-1]^{id^threshold-1} == g^share.0 * h^share.1
         // => commitment_coeffs[0] * commitment_coeffs[1]^id * commitment_coeffs[2]^{id^2} * ... commitment_coeffs[threshold-1]^{id^threshold-1} * {g^share.0 * h^share.1}^-1 == 1
 
@@ -82,7 +138,10 @@ impl PedersenVSS {
 
         let mut bases = G1Vector::with_capacity(threshold + 2);
         for i in 0..threshold {
-            bases.push(commitment_coeffs[&i].clone())
+            match commitment_coeffs.get(&i) {
+                Some(c) => bases.push(c.clone()),
+                None => return false,
+            }
         }
 
         // g^share.0 and h^share.1 will need to be inverted. To do one multi-scalar multiplication,invert g and h
@@ -103,7 +162,7 @@ mod tests {
         let threshold = 5;
         let total = 10;
         let (g, h) = PedersenVSS::gens("test".as_bytes());
-        let (secret, _, comm_coeffs, s_shares, t_shares) =
+        let (secret, _blinding, comm_coeffs, s_shares, t_shares) =
             PedersenVSS::deal(threshold, total, &g, &h);
         assert_eq!(s_shares.len(), total);
         assert_eq!(t_shares.len(), total);
@@ -1,5 +1,5 @@
 use amcl_wrapper::field_elem::{FieldElement, FieldElementVector};
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 
 pub struct Polynomial(FieldElementVector);
 
@@ -9,6 +9,11 @@ impl Polynomial {
         Self(FieldElementVector::random(degree + 1)) // +1 for constant term
     }
 
+    /// Build a polynomial from its coefficients, lowest degree term first.
+    pub fn from_coefficients(coeffs: Vec<FieldElement>) -> Self {
+        Self(FieldElementVector::from(coeffs))
+    }
+
     pub fn degree(&self) -> usize {
         self.0.len() - 1
     }
@@ -28,27 +33,193 @@ impl Polynomial {
         }
     }
 
-    /// Return the Lagrange basis polynomial at x = 0 given the x coordinates
-    pub fn lagrange_basis_at_0(x_coords: HashSet<usize>, i: usize) -> FieldElement {
+    /// Return the Lagrange basis polynomial `l_i(target) = prod_{x != i} (target - x) / (i - x)`
+    /// given the x coordinates, evaluated at an arbitrary `target` rather than just `x = 0`. This
+    /// lets reconstruction recover the secret (or any other point on the polynomial) at any
+    /// evaluation point.
+    pub fn lagrange_basis_at(x_coords: HashSet<usize>, i: usize, target: &FieldElement) -> FieldElement {
         let mut numerator = FieldElement::one();
         let mut denominator = FieldElement::one();
         let i_as_field_elem = FieldElement::from(i as u64);
-        let neg_i = -i_as_field_elem; // -i
         for x in x_coords {
             if x == i {
                 continue;
             }
-            // numerator = numerator * x
             let x_as_field_elem = FieldElement::from(x as u64);
-            numerator = &numerator * &x_as_field_elem;
-            let x_minus_i = &x_as_field_elem + &neg_i;
-            // denominator = denominator * (x - i)
-            denominator = &denominator * &x_minus_i;
+            let neg_x = -x_as_field_elem;
+            // numerator = numerator * (target - x)
+            let target_minus_x = target + &neg_x;
+            numerator = &numerator * &target_minus_x;
+            // denominator = denominator * (i - x)
+            let i_minus_x = &i_as_field_elem + &neg_x;
+            denominator = &denominator * &i_minus_x;
         }
         denominator.inverse_mut();
-        // (x_coords[0]) * (x_coords[1]) * ... / ((x_coords[0] - i) * (x_coords[1] - i) * ...)
+        // (target - x_coords[0]) * (target - x_coords[1]) * ... / ((i - x_coords[0]) * (i - x_coords[1]) * ...)
         numerator * denominator
     }
+
+    /// Return the Lagrange basis polynomial at x = 0 given the x coordinates
+    pub fn lagrange_basis_at_0(x_coords: HashSet<usize>, i: usize) -> FieldElement {
+        Self::lagrange_basis_at(x_coords, i, &FieldElement::zero())
+    }
+
+    /// Recover the unique polynomial of degree `< points.len()` passing through every `(x, y)` pair,
+    /// via Lagrange interpolation.
+    pub fn interpolate(points: &[(FieldElement, FieldElement)]) -> Self {
+        let mut result = Polynomial::from_coefficients(vec![FieldElement::zero()]);
+        for i in 0..points.len() {
+            let (x_i, y_i) = &points[i];
+            // basis_i(x) = prod_{j != i} (x - x_j), to be scaled below by 1 / prod_{j != i} (x_i - x_j)
+            let mut basis = Polynomial::from_coefficients(vec![FieldElement::one()]);
+            let mut denominator = FieldElement::one();
+            for (j, (x_j, _)) in points.iter().enumerate() {
+                if j == i {
+                    continue;
+                }
+                // (x - x_j) as a polynomial, lowest degree term first
+                let factor = Polynomial::from_coefficients(vec![-x_j.clone(), FieldElement::one()]);
+                basis = basis.mul(&factor);
+                let neg_x_j = -x_j.clone();
+                let x_i_minus_x_j = x_i + &neg_x_j;
+                denominator = &denominator * &x_i_minus_x_j;
+            }
+            denominator.inverse_mut();
+            let scaled = basis.scalar_mul(&(y_i * &denominator));
+            result = result.add(&scaled);
+        }
+        result
+    }
+
+    fn coefficient_or_zero(&self, i: usize) -> FieldElement {
+        if i < self.0.len() {
+            self.0[i].clone()
+        } else {
+            FieldElement::zero()
+        }
+    }
+
+    /// Drop trailing zero coefficients, keeping at least a constant term.
+    fn trim(mut coeffs: Vec<FieldElement>) -> Vec<FieldElement> {
+        while coeffs.len() > 1 && coeffs.last().unwrap().is_zero() {
+            coeffs.pop();
+        }
+        coeffs
+    }
+
+    pub fn add(&self, other: &Self) -> Self {
+        let len = std::cmp::max(self.0.len(), other.0.len());
+        let coeffs = (0..len)
+            .map(|i| &self.coefficient_or_zero(i) + &other.coefficient_or_zero(i))
+            .collect::<Vec<FieldElement>>();
+        Self::from_coefficients(Self::trim(coeffs))
+    }
+
+    pub fn sub(&self, other: &Self) -> Self {
+        let len = std::cmp::max(self.0.len(), other.0.len());
+        let coeffs = (0..len)
+            .map(|i| {
+                let neg = -other.coefficient_or_zero(i);
+                &self.coefficient_or_zero(i) + &neg
+            })
+            .collect::<Vec<FieldElement>>();
+        Self::from_coefficients(Self::trim(coeffs))
+    }
+
+    /// Schoolbook convolution of the coefficient vectors.
+    pub fn mul(&self, other: &Self) -> Self {
+        let mut coeffs = vec![FieldElement::zero(); self.0.len() + other.0.len() - 1];
+        for i in 0..self.0.len() {
+            for j in 0..other.0.len() {
+                coeffs[i + j] += &(&self.0[i] * &other.0[j]);
+            }
+        }
+        Self::from_coefficients(Self::trim(coeffs))
+    }
+
+    pub fn scalar_mul(&self, scalar: &FieldElement) -> Self {
+        let coeffs = (0..self.0.len())
+            .map(|i| &self.0[i] * scalar)
+            .collect::<Vec<FieldElement>>();
+        Self::from_coefficients(coeffs)
+    }
+}
+
+// A symmetric bivariate polynomial f(x, y) = sum_{i=0}^{t} sum_{j=0}^{t} a_{ij}.x^i.y^j of degree
+// `t` in each variable, with a_{ij} = a_{ji}. Used by the bivariate-polynomial DKG to let a dealer
+// hand each node a full row `f(m, y)` that is itself verifiable against a public commitment.
+// Only the `(t+1)(t+2)/2` independent coefficients (those with i <= j) are stored.
+pub struct BivarPolynomial {
+    degree: usize,
+    coeffs: HashMap<(usize, usize), FieldElement>,
+}
+
+impl BivarPolynomial {
+    /// Return a randomly chosen symmetric bivariate polynomial of degree `degree` in each variable.
+    pub fn random(degree: usize) -> Self {
+        let mut coeffs = HashMap::new();
+        for i in 0..=degree {
+            for j in i..=degree {
+                coeffs.insert((i, j), FieldElement::random());
+            }
+        }
+        Self { degree, coeffs }
+    }
+
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    /// Return coefficient a_{ij}, relying on symmetry a_{ij} = a_{ji}.
+    pub fn coefficient(&self, i: usize, j: usize) -> &FieldElement {
+        if i <= j {
+            &self.coeffs[&(i, j)]
+        } else {
+            &self.coeffs[&(j, i)]
+        }
+    }
+
+    /// Evaluate f(x, y) at the given point.
+    pub fn eval(&self, x: &FieldElement, y: &FieldElement) -> FieldElement {
+        let x_exp = Self::powers(x, self.degree + 1);
+        let y_exp = Self::powers(y, self.degree + 1);
+        let mut result = FieldElement::zero();
+        for i in 0..=self.degree {
+            for j in 0..=self.degree {
+                result += self.coefficient(i, j) * &x_exp[i] * &y_exp[j];
+            }
+        }
+        result
+    }
+
+    /// `[1, x, x^2, ... x^{n-1}]`. Unlike `FieldElementVector::new_vandermonde_vector`, this is
+    /// correct when `x` is 0 (that builtin returns an all-zero vector in that case, losing the
+    /// `x^0 = 1` term).
+    fn powers(x: &FieldElement, n: usize) -> FieldElementVector {
+        let mut v = Vec::with_capacity(n);
+        let mut cur = FieldElement::one();
+        for _ in 0..n {
+            v.push(cur.clone());
+            cur = &cur * x;
+        }
+        v.into()
+    }
+
+    /// Return the univariate polynomial f(m, y) obtained by fixing `x = m`. This is the row
+    /// the dealer sends to node `m` in the bivariate-polynomial DKG.
+    pub fn row(&self, m: usize) -> Polynomial {
+        let m_exp = Self::powers(&FieldElement::from(m as u64), self.degree + 1);
+        let row_coeffs = (0..=self.degree)
+            .map(|j| {
+                let mut c = FieldElement::zero();
+                for i in 0..=self.degree {
+                    c += self.coefficient(i, j) * &m_exp[i];
+                }
+                c
+            })
+            .collect::<Vec<FieldElement>>();
+        Polynomial::from_coefficients(row_coeffs)
+    }
 }
 
 #[cfg(test)]
@@ -70,4 +241,77 @@ mod tests {
             assert_eq!(poly.eval(&FieldElement::one()), coeffs.sum());
         }
     }
+
+    #[test]
+    fn test_poly_arithmetic() {
+        // (1 + 2x) + (3 + 4x + 5x^2) = (4 + 6x + 5x^2)
+        let a = Polynomial::from_coefficients(vec![FieldElement::from(1u64), FieldElement::from(2u64)]);
+        let b = Polynomial::from_coefficients(vec![
+            FieldElement::from(3u64),
+            FieldElement::from(4u64),
+            FieldElement::from(5u64),
+        ]);
+        let sum = a.add(&b);
+        assert_eq!(sum.degree(), 2);
+        for x in 0..5 {
+            let x = FieldElement::from(x as u64);
+            assert_eq!(sum.eval(&x), &a.eval(&x) + &b.eval(&x));
+        }
+
+        let diff = b.sub(&a);
+        for x in 0..5 {
+            let x = FieldElement::from(x as u64);
+            let expected = &b.eval(&x) + &-a.eval(&x);
+            assert_eq!(diff.eval(&x), expected);
+        }
+
+        let product = a.mul(&b);
+        assert_eq!(product.degree(), a.degree() + b.degree());
+        for x in 0..5 {
+            let x = FieldElement::from(x as u64);
+            assert_eq!(product.eval(&x), &a.eval(&x) * &b.eval(&x));
+        }
+
+        let scaled = a.scalar_mul(&FieldElement::from(10u64));
+        for x in 0..5 {
+            let x = FieldElement::from(x as u64);
+            assert_eq!(scaled.eval(&x), &a.eval(&x) * &FieldElement::from(10u64));
+        }
+    }
+
+    #[test]
+    fn test_poly_interpolate() {
+        for _ in 0..10 {
+            let degree = 7;
+            let poly = Polynomial::random(degree);
+            let points = (1..=degree + 1)
+                .map(|x| {
+                    let x = FieldElement::from(x as u64);
+                    let y = poly.eval(&x);
+                    (x, y)
+                })
+                .collect::<Vec<(FieldElement, FieldElement)>>();
+            let recovered = Polynomial::interpolate(&points);
+            assert_eq!(recovered.degree(), poly.degree());
+            for x in 0..20 {
+                let x = FieldElement::from(x as u64);
+                assert_eq!(recovered.eval(&x), poly.eval(&x));
+            }
+        }
+    }
+
+    #[test]
+    fn test_lagrange_basis_at_arbitrary_target() {
+        let degree = 5;
+        let poly = Polynomial::random(degree);
+        let x_coords = (1..=degree + 1).collect::<HashSet<usize>>();
+        let target = FieldElement::from(42u64);
+
+        let mut result = FieldElement::zero();
+        for i in x_coords.clone() {
+            let l = Polynomial::lagrange_basis_at(x_coords.clone(), i, &target);
+            result += &(&l * &poly.eval(&FieldElement::from(i as u64)));
+        }
+        assert_eq!(result, poly.eval(&target));
+    }
 }
\ No newline at end of file
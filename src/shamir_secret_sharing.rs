@@ -17,6 +17,25 @@ pub fn get_shared_secret_with_polynomial(
     (secret, shares, random_poly)
 }
 
+/// Generate a random polynomial whose constant term is forced to 0, i.e. a `k`-of-`n` sharing of
+/// the value zero. Used for proactive share refresh: summing shares of zero into existing shares
+/// leaves the secret they reconstruct to unchanged.
+pub fn get_shares_of_zero_with_polynomial(
+    threshold: usize,
+    total: usize,
+) -> (HashMap<usize, FieldElement>, Polynomial) {
+    let random_poly = Polynomial::random(threshold - 1);
+    let mut coeffs = (0..=random_poly.degree())
+        .map(|i| random_poly.coefficients()[i].clone())
+        .collect::<Vec<FieldElement>>();
+    coeffs[0] = FieldElement::zero();
+    let zero_poly = Polynomial::from_coefficients(coeffs);
+    let shares = (1..=total)
+        .map(|x| (x, zero_poly.eval(&FieldElement::from(x as u64))))
+        .collect::<HashMap<usize, FieldElement>>();
+    (shares, zero_poly)
+}
+
 /// Generate a secret with its shares according to Shamir secret sharing.
 /// Returns the secret and a map of share_id -> share
 pub fn get_shared_secret(